@@ -0,0 +1,50 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Raw `cf.tlsClientAuth` object present on requests to a Worker sitting behind a zone
+    /// with mTLS enabled. All fields are reported by the edge as strings, including the
+    /// boolean-flavored ones (e.g. `certPresented` is `"1"` or `"0"`).
+    #[wasm_bindgen(extends=js_sys::Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type TlsClientAuth;
+
+    #[wasm_bindgen(method, getter, js_name=certPresented)]
+    pub fn cert_presented(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certVerified)]
+    pub fn cert_verified(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certRevoked)]
+    pub fn cert_revoked(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certSubjectDN)]
+    pub fn cert_subject_dn(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certIssuerDN)]
+    pub fn cert_issuer_dn(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certSubjectDNRFC2253)]
+    pub fn cert_subject_dn_rfc2253(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certIssuerDNRFC2253)]
+    pub fn cert_issuer_dn_rfc2253(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certSerial)]
+    pub fn cert_serial(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certFingerprintSHA1)]
+    pub fn cert_fingerprint_sha1(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certFingerprintSHA256)]
+    pub fn cert_fingerprint_sha256(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certNotBefore)]
+    pub fn cert_not_before(this: &TlsClientAuth) -> String;
+
+    #[wasm_bindgen(method, getter, js_name=certNotAfter)]
+    pub fn cert_not_after(this: &TlsClientAuth) -> String;
+}
+
+unsafe impl Send for TlsClientAuth {}
+unsafe impl Sync for TlsClientAuth {}
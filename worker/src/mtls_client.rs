@@ -0,0 +1,234 @@
+use crate::{Error, Fetch, MtlsCertificate, Request, RequestInit, Result, Url};
+
+#[cfg(feature = "http")]
+use crate::HttpResponse;
+use crate::Response;
+
+#[cfg(not(feature = "http"))]
+type FetchResponseType = Response;
+#[cfg(feature = "http")]
+type FetchResponseType = HttpResponse;
+
+/// A client that selects an [`MtlsCertificate`] binding to present based on the destination
+/// host of a request.
+///
+/// [`MtlsCertificate::fetch`] requires picking the right binding by hand for every call, which
+/// gets error-prone once a Worker talks to several mutually-authenticated upstreams.
+/// `MtlsClient` lets you register identities once, keyed by host pattern, and reuse them
+/// across requests the way you would a `reqwest::Client` — falling back to an ordinary
+/// [`Fetch`] for any host that doesn't match a registered pattern (or to the configured
+/// default, if any).
+///
+/// When a host matches more than one registered pattern, selection is deterministic: an
+/// exact host match always wins over a wildcard, and among wildcards the one with the
+/// longest (most specific) suffix wins — e.g. `*.payments.example.com` beats `*.example.com`
+/// for `foo.payments.example.com`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use worker::*;
+/// # async fn example(env: Env) -> Result<Response> {
+/// let client = MtlsClient::builder()
+///     .with_host("api.internal.example.com", env.get_binding::<MtlsCertificate>("API_CERT")?)
+///     .with_host("*.payments.example.com", env.get_binding::<MtlsCertificate>("PAYMENTS_CERT")?)
+///     .build();
+///
+/// let response = client.fetch("https://api.internal.example.com/orders", None).await?;
+/// # Ok(response.into())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MtlsClient {
+    routes: Vec<(HostPattern, MtlsCertificate)>,
+    default: Option<MtlsCertificate>,
+}
+
+impl MtlsClient {
+    /// Creates a new, empty [`MtlsClientBuilder`].
+    pub fn builder() -> MtlsClientBuilder {
+        MtlsClientBuilder::default()
+    }
+
+    /// Makes a request to `url`, presenting whichever certificate is bound to its host, or
+    /// falling back to an unauthenticated [`Fetch`] if no binding matches.
+    pub async fn fetch(
+        &self,
+        url: impl Into<String>,
+        init: Option<RequestInit>,
+    ) -> Result<FetchResponseType> {
+        let url = url.into();
+        match self.certificate_for(&url)? {
+            Some(cert) => cert.fetch(url, init).await,
+            None => {
+                let parsed = Url::parse(&url).map_err(|e| Error::RustError(e.to_string()))?;
+                let request = match init {
+                    Some(ref init) => {
+                        Fetch::Request(Request::new_with_init(parsed.as_str(), init)?)
+                    }
+                    None => Fetch::Url(parsed),
+                };
+                send(request).await
+            }
+        }
+    }
+
+    /// Makes a request using an existing [`Request`], presenting whichever certificate is
+    /// bound to its host, or falling back to an unauthenticated [`Fetch`] if no binding matches.
+    pub async fn fetch_request<T, E>(&self, request: T) -> Result<FetchResponseType>
+    where
+        T: TryInto<Request, Error = E>,
+        crate::Error: From<E>,
+    {
+        let req = request.try_into()?;
+        match self.certificate_for(&req.url()?.to_string())? {
+            Some(cert) => cert.fetch_request(req).await,
+            None => send(Fetch::Request(req)).await,
+        }
+    }
+
+    fn certificate_for(&self, url: &str) -> Result<Option<&MtlsCertificate>> {
+        let host = Url::parse(url)
+            .map_err(|e| Error::RustError(e.to_string()))?
+            .host_str()
+            .map(str::to_owned)
+            .unwrap_or_default();
+
+        let best_match = self
+            .routes
+            .iter()
+            .filter(|(pattern, _)| pattern.matches(&host))
+            .max_by_key(|(pattern, _)| pattern.specificity());
+
+        Ok(best_match.map(|(_, cert)| cert).or(self.default.as_ref()))
+    }
+}
+
+async fn send(request: Fetch) -> Result<FetchResponseType> {
+    #[cfg(not(feature = "http"))]
+    let result = request.send().await;
+    #[cfg(feature = "http")]
+    let result = request.send().await?.try_into();
+    result
+}
+
+/// Builder for [`MtlsClient`].
+#[derive(Debug, Clone, Default)]
+pub struct MtlsClientBuilder {
+    routes: Vec<(HostPattern, MtlsCertificate)>,
+    default: Option<MtlsCertificate>,
+}
+
+impl MtlsClientBuilder {
+    /// Registers `cert` as the binding to present for requests to `host`.
+    ///
+    /// `host` may be an exact hostname (`"api.example.com"`) or a wildcard suffix
+    /// (`"*.internal.example.com"`) matching any subdomain. Registering the same pattern
+    /// twice replaces the earlier binding.
+    pub fn with_host(mut self, host: impl AsRef<str>, cert: MtlsCertificate) -> Self {
+        let pattern = HostPattern::parse(host.as_ref());
+        self.routes.retain(|(existing, _)| existing != &pattern);
+        self.routes.push((pattern, cert));
+        self
+    }
+
+    /// Sets the certificate presented for hosts that don't match any registered pattern.
+    ///
+    /// Without a default, unmatched hosts fall back to an unauthenticated [`Fetch`].
+    pub fn with_default(mut self, cert: MtlsCertificate) -> Self {
+        self.default = Some(cert);
+        self
+    }
+
+    /// Builds the configured [`MtlsClient`].
+    pub fn build(self) -> MtlsClient {
+        MtlsClient {
+            routes: self.routes,
+            default: self.default,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum HostPattern {
+    Exact(String),
+    WildcardSuffix(String),
+}
+
+impl HostPattern {
+    fn parse(pattern: &str) -> Self {
+        match pattern.strip_prefix("*.") {
+            Some(suffix) => HostPattern::WildcardSuffix(suffix.to_lowercase()),
+            None => HostPattern::Exact(pattern.to_lowercase()),
+        }
+    }
+
+    fn matches(&self, host: &str) -> bool {
+        let host = host.to_lowercase();
+        match self {
+            HostPattern::Exact(exact) => *exact == host,
+            HostPattern::WildcardSuffix(suffix) => host
+                .strip_suffix(suffix.as_str())
+                .and_then(|prefix| prefix.strip_suffix('.'))
+                .is_some(),
+        }
+    }
+
+    /// Orders matches so an exact host always outranks a wildcard, and among wildcards the
+    /// longest (most specific) suffix wins.
+    fn specificity(&self) -> (bool, usize) {
+        match self {
+            HostPattern::Exact(exact) => (true, exact.len()),
+            HostPattern::WildcardSuffix(suffix) => (false, suffix.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_pattern_matches_only_the_exact_host() {
+        let pattern = HostPattern::parse("api.example.com");
+        assert!(pattern.matches("api.example.com"));
+        assert!(!pattern.matches("foo.api.example.com"));
+        assert!(!pattern.matches("example.com"));
+    }
+
+    #[test]
+    fn wildcard_pattern_matches_any_subdomain_but_not_the_bare_suffix() {
+        let pattern = HostPattern::parse("*.example.com");
+        assert!(pattern.matches("api.example.com"));
+        assert!(pattern.matches("foo.bar.example.com"));
+        assert!(!pattern.matches("example.com"));
+        assert!(!pattern.matches("notexample.com"));
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        let exact = HostPattern::parse("API.Example.COM");
+        assert!(exact.matches("api.example.com"));
+
+        let wildcard = HostPattern::parse("*.Example.com");
+        assert!(wildcard.matches("API.EXAMPLE.COM"));
+    }
+
+    #[test]
+    fn exact_outranks_wildcard_regardless_of_length() {
+        let exact = HostPattern::parse("a.com");
+        let wildcard = HostPattern::parse("*.much-longer-suffix.example.com");
+        assert!(exact.specificity() > wildcard.specificity());
+    }
+
+    #[test]
+    fn longest_wildcard_suffix_wins_among_overlapping_wildcards() {
+        let broad = HostPattern::parse("*.example.com");
+        let specific = HostPattern::parse("*.payments.example.com");
+        assert!(specific.specificity() > broad.specificity());
+
+        let host = "foo.payments.example.com";
+        assert!(broad.matches(host));
+        assert!(specific.matches(host));
+    }
+}
@@ -0,0 +1,10 @@
+//! X.509 certificate parsing.
+//!
+//! This module is independent of the `cf.tlsClientAuth` metadata exposed by
+//! [`Request::tls_client_auth`](crate::Request::tls_client_auth): it decodes raw certificate
+//! bytes, whether those come from an upstream response or another out-of-band source, and
+//! exposes structured fields instead of opaque DN strings.
+
+mod certificate;
+
+pub use certificate::*;
@@ -0,0 +1,250 @@
+use crate::{Request, Result};
+use std::time::{Duration, SystemTime};
+use wasm_bindgen::{JsCast, JsValue};
+
+#[cfg(feature = "http")]
+use crate::HttpRequest;
+
+/// Verified client-certificate metadata attached to an incoming request by a Cloudflare
+/// zone with mTLS enabled, read from `request.cf.tlsClientAuth`.
+///
+/// This only reflects a certificate that Cloudflare itself verified at the edge; it has
+/// nothing to do with the *outgoing* client certificates presented by [`MtlsCertificate`](crate::MtlsCertificate).
+/// It mirrors the validated-client-certificate request guard that Rocket exposes to route
+/// handlers, letting a Worker gate a handler on a verified client identity.
+///
+/// # Example
+///
+/// ```no_run
+/// # use worker::*;
+/// # fn example(req: &Request) -> Result<()> {
+/// if let Some(auth) = req.tls_client_auth()? {
+///     if auth.cert_presented() && auth.cert_verified() == Verified::Success {
+///         console_log!("authenticated as {:?}", auth.cert_subject_dn());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TlsClientAuth(worker_sys::TlsClientAuth);
+
+/// The result of verifying a presented client certificate against the zone's configured CA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verified {
+    /// The certificate was successfully verified.
+    Success,
+    /// No client certificate was presented.
+    None,
+    /// Verification was attempted and failed, with the reason reported by the edge.
+    Failed(String),
+}
+
+impl From<String> for Verified {
+    fn from(raw: String) -> Self {
+        match raw.as_str() {
+            "SUCCESS" => Verified::Success,
+            "NONE" => Verified::None,
+            _ => match raw.strip_prefix("FAILED:") {
+                Some(reason) => Verified::Failed(reason.to_string()),
+                None => Verified::Failed(raw),
+            },
+        }
+    }
+}
+
+impl TlsClientAuth {
+    /// Whether the client presented a certificate at all.
+    pub fn cert_presented(&self) -> bool {
+        self.0.cert_presented() == "1"
+    }
+
+    /// The result of verifying the presented certificate against the zone's CA.
+    pub fn cert_verified(&self) -> Verified {
+        Verified::from(self.0.cert_verified())
+    }
+
+    /// Whether the presented certificate has been revoked, when revocation checking is enabled.
+    pub fn cert_revoked(&self) -> bool {
+        self.0.cert_revoked() == "1"
+    }
+
+    /// The certificate subject's distinguished name.
+    pub fn cert_subject_dn(&self) -> String {
+        self.0.cert_subject_dn()
+    }
+
+    /// The certificate issuer's distinguished name.
+    pub fn cert_issuer_dn(&self) -> String {
+        self.0.cert_issuer_dn()
+    }
+
+    /// The certificate subject's distinguished name, RFC 2253-formatted.
+    pub fn cert_subject_dn_rfc2253(&self) -> String {
+        self.0.cert_subject_dn_rfc2253()
+    }
+
+    /// The certificate issuer's distinguished name, RFC 2253-formatted.
+    pub fn cert_issuer_dn_rfc2253(&self) -> String {
+        self.0.cert_issuer_dn_rfc2253()
+    }
+
+    /// The certificate's serial number.
+    pub fn cert_serial(&self) -> String {
+        self.0.cert_serial()
+    }
+
+    /// The certificate's SHA-1 fingerprint.
+    pub fn cert_fingerprint_sha1(&self) -> String {
+        self.0.cert_fingerprint_sha1()
+    }
+
+    /// The certificate's SHA-256 fingerprint.
+    pub fn cert_fingerprint_sha256(&self) -> String {
+        self.0.cert_fingerprint_sha256()
+    }
+
+    /// The start of the certificate's validity period, if parseable.
+    pub fn cert_not_before(&self) -> Option<SystemTime> {
+        parse_cert_time(&self.0.cert_not_before())
+    }
+
+    /// The end of the certificate's validity period, if parseable.
+    pub fn cert_not_after(&self) -> Option<SystemTime> {
+        parse_cert_time(&self.0.cert_not_after())
+    }
+}
+
+/// Parses a `cf.tlsClientAuth` timestamp (e.g. `"Dec 22 19:39:00 2018 GMT"`) into a
+/// [`SystemTime`], matching how [`Certificate::validity`](crate::tls::Certificate::validity)
+/// converts ASN.1 time in the sibling certificate parser.
+fn parse_cert_time(raw: &str) -> Option<SystemTime> {
+    if raw.is_empty() {
+        return None;
+    }
+    let millis = js_sys::Date::parse(raw);
+    if millis.is_nan() {
+        return None;
+    }
+    if millis >= 0.0 {
+        Some(SystemTime::UNIX_EPOCH + Duration::from_millis(millis as u64))
+    } else {
+        Some(SystemTime::UNIX_EPOCH - Duration::from_millis(-millis as u64))
+    }
+}
+
+impl From<worker_sys::TlsClientAuth> for TlsClientAuth {
+    fn from(inner: worker_sys::TlsClientAuth) -> Self {
+        Self(inner)
+    }
+}
+
+fn tls_client_auth_from_cf(cf: &JsValue) -> Result<Option<TlsClientAuth>> {
+    if cf.is_undefined() || cf.is_null() {
+        return Ok(None);
+    }
+
+    let raw = js_sys::Reflect::get(cf, &JsValue::from_str("tlsClientAuth"))?;
+    if raw.is_undefined() || raw.is_null() {
+        return Ok(None);
+    }
+
+    Ok(Some(TlsClientAuth::from(
+        raw.unchecked_into::<worker_sys::TlsClientAuth>(),
+    )))
+}
+
+impl Request {
+    /// Returns verified client-certificate metadata from `cf.tlsClientAuth`, or `None` if the
+    /// request's `cf` object has no `tlsClientAuth` entry (e.g. the zone fronting this Worker
+    /// doesn't have mTLS enabled). When the entry is present, whether a certificate was
+    /// actually presented is reported by [`TlsClientAuth::cert_presented`].
+    pub fn tls_client_auth(&self) -> Result<Option<TlsClientAuth>> {
+        tls_client_auth_from_cf(&self.inner().cf())
+    }
+}
+
+/// The raw `cf` object of the incoming edge request, stashed as an `http::Extensions` entry
+/// when the request is converted into an [`HttpRequest`] so `cf`-derived accessors (like
+/// [`RequestExt::tls_client_auth`]) remain available once it's in `http::Request` form.
+///
+/// This is a dedicated newtype, rather than a bare [`JsValue`] extension, so lookups here
+/// can't collide with an unrelated `JsValue` inserted into the same `Extensions` map for some
+/// other purpose.
+#[cfg(feature = "http")]
+#[derive(Debug, Clone)]
+pub(crate) struct CfRaw(pub(crate) JsValue);
+
+/// Stashes `cf` into `extensions` as a [`CfRaw`] entry.
+///
+/// The `web_sys::Request` → [`HttpRequest`] conversion (alongside `response_from_wasm` on the
+/// response side) must call this with the incoming request's `cf` object so `cf`-derived
+/// accessors, like [`RequestExt::tls_client_auth`], keep working once the request has been
+/// converted into `http::Request` form. Without this call, every `RequestExt::tls_client_auth`
+/// lookup on a real incoming request silently returns `Ok(None)`.
+#[cfg(feature = "http")]
+pub(crate) fn attach_cf_extension(extensions: &mut http::Extensions, cf: JsValue) {
+    extensions.insert(CfRaw(cf));
+}
+
+/// Extension trait providing [`Request::tls_client_auth`] on `http::Request` when the `http`
+/// feature is enabled.
+#[cfg(feature = "http")]
+pub trait RequestExt {
+    /// See [`Request::tls_client_auth`].
+    fn tls_client_auth(&self) -> Result<Option<TlsClientAuth>>;
+}
+
+#[cfg(feature = "http")]
+impl RequestExt for HttpRequest {
+    fn tls_client_auth(&self) -> Result<Option<TlsClientAuth>> {
+        match self.extensions().get::<CfRaw>() {
+            Some(cf) => tls_client_auth_from_cf(&cf.0),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn cf_with_tls_client_auth() -> JsValue {
+        let tls_client_auth = js_sys::Object::new();
+        js_sys::Reflect::set(
+            &tls_client_auth,
+            &JsValue::from_str("certPresented"),
+            &JsValue::from_str("1"),
+        )
+        .unwrap();
+        js_sys::Reflect::set(
+            &tls_client_auth,
+            &JsValue::from_str("certVerified"),
+            &JsValue::from_str("SUCCESS"),
+        )
+        .unwrap();
+
+        let cf = js_sys::Object::new();
+        js_sys::Reflect::set(&cf, &JsValue::from_str("tlsClientAuth"), &tls_client_auth).unwrap();
+        cf.into()
+    }
+
+    #[wasm_bindgen_test]
+    fn tls_client_auth_reads_the_cf_raw_extension_attached_by_the_wasm_conversion() {
+        // Exercises the same `attach_cf_extension` call the real `web_sys::Request` ->
+        // `HttpRequest` conversion is required to make, rather than hand-inserting `CfRaw`.
+        let mut req = HttpRequest::new(crate::Body::empty());
+        attach_cf_extension(req.extensions_mut(), cf_with_tls_client_auth());
+
+        let auth = req.tls_client_auth().unwrap().unwrap();
+        assert!(auth.cert_presented());
+        assert_eq!(auth.cert_verified(), Verified::Success);
+    }
+
+    #[wasm_bindgen_test]
+    fn tls_client_auth_is_none_without_the_cf_raw_extension() {
+        let req = HttpRequest::new(crate::Body::empty());
+        assert!(req.tls_client_auth().unwrap().is_none());
+    }
+}